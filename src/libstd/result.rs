@@ -14,11 +14,12 @@
 
 use clone::Clone;
 use cmp::Eq;
+use default::Default;
 use either;
 use iterator::Iterator;
 use option::{None, Option, Some, OptionIterator};
 use vec;
-use vec::{OwnedVector, ImmutableVector};
+use vec::OwnedVector;
 use container::Container;
 use to_str::ToStr;
 use str::StrSlice;
@@ -91,10 +92,7 @@ impl<T, E: ToStr> Result<T, E> {
     ///     }
     #[inline]
     pub fn iter<'r>(&'r self) -> OptionIterator<&'r T> {
-        match *self {
-            Ok(ref t) => Some(t),
-            Err(*) => None,
-        }.consume()
+        self.as_ref().ok().consume()
     }
 
     /// Call a method based on a previous result
@@ -105,10 +103,7 @@ impl<T, E: ToStr> Result<T, E> {
     /// successful result while handling an error.
     #[inline]
     pub fn iter_err<'r>(&'r self) -> OptionIterator<&'r E> {
-        match *self {
-            Ok(*) => None,
-            Err(ref t) => Some(t),
-        }.consume()
+        self.as_ref().err().consume()
     }
 
     /// Unwraps a result, yielding the content of an `Ok`.
@@ -218,6 +213,151 @@ impl<T, E: ToStr> Result<T, E> {
     }
 }
 
+impl<T, E> Result<T, E> {
+    /// Converts from `Result<T, E>` to `Option<T>`, discarding the error, if any.
+    #[inline]
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Ok(t) => Some(t),
+            Err(_) => None,
+        }
+    }
+
+    /// Converts from `Result<T, E>` to `Option<E>`, discarding the success value, if any.
+    #[inline]
+    pub fn err(self) -> Option<E> {
+        match self {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    /// Converts from `&Result<T, E>` to `Result<&T, &E>`, without consuming
+    /// or cloning `self`.
+    #[inline]
+    pub fn as_ref<'r>(&'r self) -> Result<&'r T, &'r E> {
+        match *self {
+            Ok(ref t) => Ok(t),
+            Err(ref e) => Err(e),
+        }
+    }
+
+    /// Converts from `&mut Result<T, E>` to `Result<&mut T, &mut E>`,
+    /// without consuming or cloning `self`.
+    #[inline]
+    pub fn as_mut<'r>(&'r mut self) -> Result<&'r mut T, &'r mut E> {
+        match *self {
+            Ok(ref mut t) => Ok(t),
+            Err(ref mut e) => Err(e),
+        }
+    }
+
+    /// Unwraps a result, yielding the content of an `Ok`.
+    /// Returns `def` if the value is an `Err`.
+    #[inline]
+    pub fn unwrap_or(self, def: T) -> T {
+        match self {
+            Ok(t) => t,
+            Err(_) => def,
+        }
+    }
+
+    /// Unwraps a result, yielding the content of an `Ok`.
+    /// If the value is an `Err` then it calls `op` with its error value.
+    #[inline]
+    pub fn unwrap_or_else(self, op: &fn(E) -> T) -> T {
+        match self {
+            Ok(t) => t,
+            Err(e) => op(e),
+        }
+    }
+
+    /// Applies `op` to the value if `self` is `Ok`, otherwise returns `def`.
+    ///
+    /// Unlike `map_move`, this discharges the result into a plain `U`
+    /// rather than rewrapping it in `Result`, which is convenient for
+    /// computing a single value like a display string or a status code.
+    #[inline]
+    pub fn map_or<U>(self, def: U, op: &fn(T) -> U) -> U {
+        match self {
+            Ok(t) => op(t),
+            Err(_) => def,
+        }
+    }
+
+    /// Applies `ok_op` to the value if `self` is `Ok`, or `err_op` to the
+    /// error if `self` is `Err`, collapsing either arm into a single `U`.
+    #[inline]
+    pub fn map_or_else<U>(self, err_op: &fn(E) -> U, ok_op: &fn(T) -> U) -> U {
+        match self {
+            Ok(t) => ok_op(t),
+            Err(e) => err_op(e),
+        }
+    }
+
+    /// Returns `res` if `self` is `Ok`, otherwise returns `self`'s error.
+    ///
+    /// This is the non-closure sibling of `chain`/`and_then`: use it when
+    /// `res` is already computed and there is no need to defer its
+    /// evaluation.
+    #[inline]
+    pub fn and<U>(self, res: Result<U, E>) -> Result<U, E> {
+        match self {
+            Ok(_) => res,
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Calls `op` if `self` is `Ok`, otherwise returns `self`'s error.
+    ///
+    /// This is the same operation as `chain`, renamed to match the
+    /// `and`/`and_then` combinator family.
+    #[inline]
+    pub fn and_then<U>(self, op: &fn(T) -> Result<U, E>) -> Result<U, E> {
+        match self {
+            Ok(t) => op(t),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns `self` if it is `Ok`, otherwise returns `res`.
+    ///
+    /// This is the non-closure sibling of `chain_err`/`or_else`: use it when
+    /// the fallback `res` is already computed, eg
+    /// `first_source().or(second_source())`.
+    #[inline]
+    pub fn or<F>(self, res: Result<T, F>) -> Result<T, F> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(_) => res,
+        }
+    }
+
+    /// Calls `op` if `self` is `Err`, otherwise returns `self`'s value.
+    ///
+    /// This is the same operation as `chain_err`, renamed to match the
+    /// `or`/`or_else` combinator family.
+    #[inline]
+    pub fn or_else<F>(self, op: &fn(E) -> Result<T, F>) -> Result<T, F> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(e) => op(e),
+        }
+    }
+}
+
+impl<T: Default, E> Result<T, E> {
+    /// Unwraps a result, yielding the content of an `Ok`.
+    /// Returns `T::default()` if the value is an `Err`.
+    #[inline]
+    pub fn unwrap_or_default(self) -> T {
+        match self {
+            Ok(t) => t,
+            Err(_) => Default::default(),
+        }
+    }
+}
+
 impl<T: Clone, E: ToStr> Result<T, E> {
     /// Call a method based on a previous result
     ///
@@ -269,80 +409,27 @@ pub fn map_opt<T, U: ToStr, V>(o_t: &Option<T>,
     }
 }
 
-// FIXME: #8228 Replaceable by an external iterator?
-/// Maps each element in the vector `ts` using the operation `op`.  Should an
-/// error occur, no further mappings are performed and the error is returned.
-/// Should no error occur, a vector containing the result of each map is
-/// returned.
-///
-/// Here is an example which increments every integer in a vector,
-/// checking for overflow:
-///
-///     fn inc_conditionally(x: uint) -> result<uint,str> {
-///         if x == uint::max_value { return Err("overflow"); }
-///         else { return Ok(x+1u); }
-///     }
-///     map(~[1u, 2u, 3u], inc_conditionally).chain {|incd|
-///         assert!(incd == ~[2u, 3u, 4u]);
-///     }
-#[inline]
-pub fn map_vec<T,U,V>(ts: &[T], op: &fn(&T) -> Result<V,U>)
-                      -> Result<~[V],U> {
-    let mut vs: ~[V] = vec::with_capacity(ts.len());
-    for t in ts.iter() {
-        match op(t) {
-          Ok(v) => vs.push(v),
-          Err(u) => return Err(u)
-        }
-    }
-    return Ok(vs);
-}
-
-// FIXME: #8228 Replaceable by an external iterator?
-/// Same as map, but it operates over two parallel vectors.
+/// Takes each `Result` produced by `iter` and, so long as every one is `Ok`,
+/// collects the successful values into a vector in the order they were
+/// produced. The first `Err` encountered is returned immediately and no
+/// further elements are pulled from `iter`.
 ///
-/// A precondition is used here to ensure that the vectors are the same
-/// length.  While we do not often use preconditions in the standard
-/// library, a precondition is used here because result::t is generally
-/// used in 'careful' code contexts where it is both appropriate and easy
-/// to accommodate an error like the vectors being of different lengths.
+/// This replaces the old `map_vec`/`map_vec2`/`iter_vec2` free functions,
+/// which each open-coded this same loop: `map_vec(ts, op)` is now
+/// `result::collect(ts.iter().map(op))`.
 #[inline]
-pub fn map_vec2<S, T, U: ToStr, V>(ss: &[S], ts: &[T],
-                                   op: &fn(&S,&T) -> Result<V,U>) -> Result<~[V],U> {
-    assert!(vec::same_length(ss, ts));
-    let n = ts.len();
-    let mut vs = vec::with_capacity(n);
-    let mut i = 0u;
-    while i < n {
-        match op(&ss[i],&ts[i]) {
-          Ok(v) => vs.push(v),
-          Err(u) => return Err(u)
+pub fn collect<T, E, Iter: Iterator<Result<T, E>>>(mut iter: Iter) -> Result<~[T], E> {
+    let (lower, _) = iter.size_hint();
+    let mut vs: ~[T] = vec::with_capacity(lower);
+    for x in iter {
+        match x {
+            Ok(t) => vs.push(t),
+            Err(e) => return Err(e),
         }
-        i += 1u;
     }
     return Ok(vs);
 }
 
-// FIXME: #8228 Replaceable by an external iterator?
-/// Applies op to the pairwise elements from `ss` and `ts`, aborting on
-/// error.  This could be implemented using `map_zip()` but it is more efficient
-/// on its own as no result vector is built.
-#[inline]
-pub fn iter_vec2<S, T, U: ToStr>(ss: &[S], ts: &[T],
-                                 op: &fn(&S,&T) -> Result<(),U>) -> Result<(),U> {
-    assert!(vec::same_length(ss, ts));
-    let n = ts.len();
-    let mut i = 0u;
-    while i < n {
-        match op(&ss[i],&ts[i]) {
-          Ok(()) => (),
-          Err(u) => return Err(u)
-        }
-        i += 1u;
-    }
-    return Ok(());
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -368,6 +455,30 @@ mod tests {
         assert_eq!(op3().chain( op2).unwrap_err(), ~"sadface");
     }
 
+    #[test]
+    pub fn test_and() {
+        assert_eq!(op1().and(Ok::<uint, ~str>(667)), Ok(667));
+        assert_eq!(op3().and(Ok::<uint, ~str>(667)), Err(~"sadface"));
+    }
+
+    #[test]
+    pub fn test_and_then() {
+        assert_eq!(op1().and_then(op2).unwrap(), 667u);
+        assert_eq!(op3().and_then(op2).unwrap_err(), ~"sadface");
+    }
+
+    #[test]
+    pub fn test_or() {
+        assert_eq!(op1().or(op3()), Ok(666));
+        assert_eq!(op3().or(op1()), Ok(666));
+    }
+
+    #[test]
+    pub fn test_or_else() {
+        assert_eq!(op3().or_else(|_| op1()).unwrap(), 666);
+        assert_eq!(op1().or_else(|_| op3()).unwrap(), 666);
+    }
+
     #[test]
     pub fn test_impl_iter() {
         let mut valid = false;
@@ -405,6 +516,18 @@ mod tests {
         assert_eq!(Err::<~str, ~str>(~"a").map_err(|x| (~"b").append(*x)), Err(~"ba"));
     }
 
+    #[test]
+    pub fn test_impl_map_or() {
+        assert_eq!(Ok::<~str, ~str>(~"a").map_or(~"default", |x| x + "b"), ~"ab");
+        assert_eq!(Err::<~str, ~str>(~"a").map_or(~"default", |x| x + "b"), ~"default");
+    }
+
+    #[test]
+    pub fn test_impl_map_or_else() {
+        assert_eq!(Ok::<~str, ~str>(~"a").map_or_else(|e| e + "!", |x| x + "b"), ~"ab");
+        assert_eq!(Err::<~str, ~str>(~"a").map_or_else(|e| e + "!", |x| x + "b"), ~"a!");
+    }
+
     #[test]
     pub fn test_impl_map_move() {
         assert_eq!(Ok::<~str, ~str>(~"a").map_move(|x| x + "b"), Ok(~"ab"));
@@ -417,12 +540,75 @@ mod tests {
         assert_eq!(Err::<~str, ~str>(~"a").map_err_move(|x| x + "b"), Err(~"ab"));
     }
 
+    #[test]
+    pub fn test_unwrap_or() {
+        assert_eq!(op1().unwrap_or(667), 666);
+        assert_eq!(op3().unwrap_or(667), 667);
+    }
+
+    #[test]
+    pub fn test_unwrap_or_else() {
+        assert_eq!(op1().unwrap_or_else(|_| 667), 666);
+        assert_eq!(op3().unwrap_or_else(|e| e.len() as int), 7);
+    }
+
+    #[test]
+    pub fn test_unwrap_or_default() {
+        let ok: Result<int, ~str> = Ok(666);
+        let err: Result<int, ~str> = Err(~"sadface");
+        assert_eq!(ok.unwrap_or_default(), 666);
+        assert_eq!(err.unwrap_or_default(), 0);
+    }
+
+    #[test]
+    pub fn test_as_ref() {
+        let ok: Result<int, ~str> = Ok(666);
+        let err: Result<int, ~str> = Err(~"sadface");
+        assert_eq!(ok.as_ref(), Ok(&666));
+        assert_eq!(err.as_ref(), Err(&~"sadface"));
+    }
+
+    #[test]
+    pub fn test_as_mut() {
+        let mut ok: Result<int, ~str> = Ok(666);
+        match ok.as_mut() {
+            Ok(t) => *t += 1,
+            Err(_) => fail!("unreachable"),
+        }
+        assert_eq!(ok, Ok(667));
+    }
+
     #[test]
     pub fn test_get_ref_method() {
         let foo: Result<int, ()> = Ok(100);
         assert_eq!(*foo.get_ref(), 100);
     }
 
+    #[test]
+    pub fn test_ok() {
+        let ok: Result<int, ~str> = Ok(666);
+        let err: Result<int, ~str> = Err(~"sadface");
+        assert_eq!(ok.ok(), Some(666));
+        assert_eq!(err.ok(), None);
+    }
+
+    #[test]
+    pub fn test_err() {
+        let ok: Result<int, ~str> = Ok(666);
+        let err: Result<int, ~str> = Err(~"sadface");
+        assert_eq!(ok.err(), None);
+        assert_eq!(err.err(), Some(~"sadface"));
+    }
+
+    #[test]
+    pub fn test_collect() {
+        let v: ~[Result<int, ~str>] = ~[Ok(1), Ok(2), Ok(3)];
+        assert_eq!(super::collect(v.consume_iter()), Ok(~[1, 2, 3]));
+
+        let e: ~[Result<int, ~str>] = ~[Ok(1), Err(~"nope"), Ok(3)];
+        assert_eq!(super::collect(e.consume_iter()), Err(~"nope"));
+    }
+
     #[test]
     pub fn test_to_either() {
         let r: Result<int, ()> = Ok(100);